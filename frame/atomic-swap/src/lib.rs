@@ -35,7 +35,9 @@
 //!
 //! * `create_swap` - called by a sender to register a new atomic swap
 //! * `claim_swap` - called by the target to approve a swap
+//! * `claim_swap_adaptor` - called by the target to claim an adaptor-signature swap
 //! * `cancel_swap` - may be called by a sender after a specified duration
+//! * `refund_swap` - may be called by the target to release the swap early
 
 // Ensure we're `no_std` when compiling for Wasm.
 #![cfg_attr(not(feature = "std"), no_std)]
@@ -43,12 +45,13 @@
 mod tests;
 
 use sp_std::{prelude::*, marker::PhantomData, ops::{Deref, DerefMut}};
-use sp_io::hashing::blake2_256;
+use sp_io::hashing::{blake2_256, sha2_256, keccak_256};
 use frame_support::{
-	ensure,
-	traits::{Get, Currency, ReservableCurrency, BalanceStatus},
-	weights::Weight,
-	dispatch::DispatchResult,
+	ensure, Parameter,
+	CloneNoBound, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound,
+	traits::{Get, Currency, ReservableCurrency, BalanceStatus, Filter},
+	weights::{Weight, GetDispatchInfo},
+	dispatch::{DispatchResult, Dispatchable},
 };
 use codec::{Encode, Decode};
 use sp_runtime::RuntimeDebug;
@@ -62,6 +65,10 @@ pub struct PendingSwap<T: Trait> {
 	pub source: T::AccountId,
 	/// Action of this swap.
 	pub action: T::SwapAction,
+	/// Hashed proof that the target must reveal a preimage of in order to claim the swap.
+	pub hashed_proof: HashedProof,
+	/// Hash algorithm used to produce `hashed_proof`.
+	pub hash_algorithm: HashAlgorithm,
 	/// End block of the lock.
 	pub end_block: T::BlockNumber,
 }
@@ -69,6 +76,63 @@ pub struct PendingSwap<T: Trait> {
 /// Hashed proof type.
 pub type HashedProof = [u8; 32];
 
+/// Identifier of a swap, agreed between the two parties during setup.
+///
+/// The swap id — not the hashed proof — keys the pending-swap storage, so the same pair of
+/// accounts can run several concurrent swaps that happen to reuse a hash, and two senders picking
+/// the same hashed proof no longer collide.
+pub type SwapId = [u8; 32];
+
+/// Hash algorithm used to derive a [`HashedProof`] from a revealed preimage.
+///
+/// Different chains compute the preimage hash of a hashed-timelock swap with different
+/// primitives (Bitcoin uses SHA-256 / double-SHA-256, Ethereum uses keccak-256). Recording the
+/// algorithm alongside the swap lets a single secret unlock both legs of a genuine cross-chain
+/// swap.
+#[derive(Clone, Copy, Eq, PartialEq, Encode, Decode, RuntimeDebug)]
+pub enum HashAlgorithm {
+	/// Blake2b, 256-bit output. The native hash of most Substrate chains.
+	Blake2_256,
+	/// SHA-256.
+	Sha2_256,
+	/// Double SHA-256, as used by Bitcoin script (`OP_HASH256`).
+	Sha2_256d,
+	/// Keccak-256, as used by the Ethereum Virtual Machine.
+	Keccak256,
+}
+
+impl HashAlgorithm {
+	/// Hash `data` into a [`HashedProof`] using the selected algorithm.
+	pub fn hash(&self, data: &[u8]) -> HashedProof {
+		match self {
+			HashAlgorithm::Blake2_256 => blake2_256(data),
+			HashAlgorithm::Sha2_256 => sha2_256(data),
+			HashAlgorithm::Sha2_256d => sha2_256(&sha2_256(data)),
+			HashAlgorithm::Keccak256 => keccak_256(data),
+		}
+	}
+}
+
+/// A completed Schnorr signature `(R, s)` over secp256k1, with the nonce point `R` in compressed
+/// SEC1 form.
+#[derive(Clone, RuntimeDebug, Eq, PartialEq, Encode, Decode)]
+pub struct SchnorrSignature {
+	/// Nonce point `R`, compressed SEC1 (33 bytes).
+	pub r: [u8; 33],
+	/// Signature scalar `s` (32 bytes, big endian).
+	pub s: [u8; 32],
+}
+
+/// A pre-adaptor ("encrypted") signature `(R', s')`. Completing it into a full [`SchnorrSignature`]
+/// over the same message necessarily leaks the witness scalar `t` behind the statement point `T`.
+#[derive(Clone, RuntimeDebug, Eq, PartialEq, Encode, Decode)]
+pub struct AdaptorSignature {
+	/// Pre-adaptor nonce point `R'`, compressed SEC1 (33 bytes).
+	pub r: [u8; 33],
+	/// Pre-adaptor scalar `s'` (32 bytes, big endian).
+	pub s: [u8; 32],
+}
+
 /// Definition of a pending atomic swap action. It contains the following three phrases:
 ///
 /// - **Reserve**: reserve the resources needed for a swap. This is to make sure that **Claim**
@@ -82,10 +146,35 @@ pub trait SwapAction<AccountId, T: Trait> {
 	/// Claim the reserved resources, with `source` and `target`. Returns whether the claim
 	/// succeeds.
 	fn claim(&self, source: &AccountId, target: &AccountId) -> bool;
+	/// Whether this action can be settled through the hash-preimage path (`claim`).
+	///
+	/// Actions that can only be claimed via [`claim_adaptor`](Self::claim_adaptor) return `false`,
+	/// so that `claim_swap` refuses to consume them: otherwise revealing a preimage of the stored
+	/// `hashed_proof` would delete the entry while `claim` releases nothing, stranding the reserved
+	/// funds. Defaults to `true`.
+	fn supports_hash_claim(&self) -> bool {
+		true
+	}
 	/// Weight for executing the operation.
 	fn weight(&self) -> Weight;
 	/// Cancel the resources reserved in `source`.
 	fn cancel(&self, source: &AccountId);
+	/// Claim the reserved resources via an adaptor ("scriptless") signature.
+	///
+	/// The `target` submits the pre-adaptor signature `(R', s')` and the completed signature
+	/// `(R, s)` over the stored message. An action that supports scriptless swaps verifies the
+	/// encryption relation, releases the reserved resources to `target` and returns the recovered
+	/// witness scalar `t` so the counterparty chain can finalize its leg. Actions that do not
+	/// support this mode return `None`, which is the default.
+	fn claim_adaptor(
+		&self,
+		_source: &AccountId,
+		_target: &AccountId,
+		_pre_signature: &AdaptorSignature,
+		_signature: &SchnorrSignature,
+	) -> Option<HashedProof> {
+		None
+	}
 }
 
 /// A swap action that only allows transferring balances.
@@ -136,6 +225,239 @@ impl<T: Trait, AccountId, C> SwapAction<AccountId, T> for BalanceSwapAction<Acco
 	}
 }
 
+/// A swap action that dispatches a stored runtime call when the swap is claimed.
+///
+/// Turns the pallet into a general "reveal-a-secret-to-trigger-a-transaction" primitive: instead
+/// of moving the native currency, claiming dispatches an arbitrary `T::Call` with the source as
+/// the signed origin, so swaps of assets, NFTs or any other state transition become possible. A
+/// native `bond` is reserved on the source for the duration of the swap and returned on cancel.
+///
+/// The `*NoBound` derives keep the generated `Clone`/`Eq`/`PartialEq`/`Debug` bounds off the
+/// runtime type `T` (which is not `Clone`/`Debug`), bounding only on the stored `T::Call` instead,
+/// just as [`PendingSwap`] does for `Debug`.
+#[derive(Encode, Decode, CloneNoBound, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound)]
+pub struct CallSwapAction<T: Trait, C: ReservableCurrency<T::AccountId>> {
+	call: Box<<T as Trait>::Call>,
+	bond: <C as Currency<T::AccountId>>::Balance,
+	_marker: PhantomData<C>,
+}
+
+impl<T: Trait, C> CallSwapAction<T, C> where C: ReservableCurrency<T::AccountId> {
+	/// Create a new call swap action dispatching `call` on claim, backed by `bond`.
+	pub fn new(call: Box<<T as Trait>::Call>, bond: <C as Currency<T::AccountId>>::Balance) -> Self {
+		Self { call, bond, _marker: PhantomData }
+	}
+}
+
+impl<T: Trait, C> SwapAction<T::AccountId, T> for CallSwapAction<T, C>
+	where C: ReservableCurrency<T::AccountId>
+{
+	fn reserve(&self, source: &T::AccountId) -> DispatchResult {
+		// Gate the call at creation time, so a swap wrapping a call the runtime has not whitelisted
+		// can never be stored in the first place.
+		ensure!(T::CallFilter::filter(&self.call), "call not permitted by swap filter");
+		C::reserve(&source, self.bond)
+	}
+
+	fn claim(&self, source: &T::AccountId, _target: &T::AccountId) -> bool {
+		// The bond only backs the reservation while the swap is pending; once it is claimed the
+		// storage entry is removed. Release it unconditionally — before the filter guard — so the
+		// bond can never be stranded even if a call slipped past the creation-time check.
+		C::unreserve(source, self.bond);
+
+		if !T::CallFilter::filter(&self.call) {
+			return false
+		}
+
+		let origin = frame_system::RawOrigin::Signed(source.clone()).into();
+		self.call.clone().dispatch(origin).is_ok()
+	}
+
+	fn weight(&self) -> Weight {
+		self.call.get_dispatch_info().weight
+			.saturating_add(T::DbWeight::get().reads_writes(1, 1))
+	}
+
+	fn cancel(&self, source: &T::AccountId) {
+		C::unreserve(source, self.bond);
+	}
+}
+
+/// A scriptless swap action settled by an adaptor (discrete-log) signature rather than a hash
+/// preimage.
+///
+/// Hash-preimage HTLCs require both chains to support the same hash primitive and leak a linkable
+/// preimage. An adaptor-signature swap instead locks against a secp256k1 statement point
+/// `T = t·G`: completing a signature over the stored message on-chain is impossible without
+/// publishing a value that deterministically reveals the witness scalar `t`, which the
+/// counterparty then uses to finalize the other leg. The native `bond` is reserved on the source
+/// for the duration of the swap.
+#[derive(Clone, RuntimeDebug, Eq, PartialEq, Encode, Decode)]
+pub struct AdaptorSwapAction<AccountId, C: ReservableCurrency<AccountId>> {
+	/// Statement point `T = t·G`, compressed SEC1 (33 bytes).
+	statement: [u8; 33],
+	/// Public key `P` the signature verifies against, compressed SEC1 (33 bytes).
+	pubkey: [u8; 33],
+	/// Message `m` being signed.
+	message: Vec<u8>,
+	/// Native bond reserved on the source.
+	value: <C as Currency<AccountId>>::Balance,
+	_marker: PhantomData<C>,
+}
+
+/// Maximum length of the message stored inside an [`AdaptorSwapAction`], bounding the on-chain
+/// storage a single swap can occupy. The message is only a signing challenge domain, so this is
+/// generous.
+pub const ADAPTOR_MESSAGE_LIMIT: usize = 1024;
+
+impl<AccountId, C> AdaptorSwapAction<AccountId, C> where C: ReservableCurrency<AccountId> {
+	/// Create a new adaptor swap action locked against statement point `statement` and public key
+	/// `pubkey` over `message`, backed by `value`.
+	pub fn new(
+		statement: [u8; 33],
+		pubkey: [u8; 33],
+		message: Vec<u8>,
+		value: <C as Currency<AccountId>>::Balance,
+	) -> Self {
+		Self { statement, pubkey, message, value, _marker: PhantomData }
+	}
+}
+
+impl<T: Trait, AccountId, C> SwapAction<AccountId, T> for AdaptorSwapAction<AccountId, C>
+	where C: ReservableCurrency<AccountId>
+{
+	fn reserve(&self, source: &AccountId) -> DispatchResult {
+		ensure!(self.message.len() <= ADAPTOR_MESSAGE_LIMIT, "adaptor message too large");
+		C::reserve(&source, self.value)
+	}
+
+	fn claim(&self, _source: &AccountId, _target: &AccountId) -> bool {
+		// An adaptor swap can only be settled through `claim_adaptor`, which requires the
+		// completed signature. The plain hash-preimage claim path never succeeds for it.
+		false
+	}
+
+	fn supports_hash_claim(&self) -> bool {
+		false
+	}
+
+	fn weight(&self) -> Weight {
+		T::DbWeight::get().reads_writes(1, 1).saturating_add(100_000_000)
+	}
+
+	fn cancel(&self, source: &AccountId) {
+		C::unreserve(source, self.value);
+	}
+
+	fn claim_adaptor(
+		&self,
+		source: &AccountId,
+		target: &AccountId,
+		pre_signature: &AdaptorSignature,
+		signature: &SchnorrSignature,
+	) -> Option<HashedProof> {
+		let witness = adaptor::recover_witness(
+			&self.statement,
+			&self.pubkey,
+			&self.message,
+			pre_signature,
+			signature,
+		)?;
+
+		if C::repatriate_reserved(source, target, self.value, BalanceStatus::Free).is_ok() {
+			Some(witness)
+		} else {
+			None
+		}
+	}
+}
+
+/// secp256k1 adaptor-signature verification.
+///
+/// Verification is only available when the `secp256k1` feature is enabled; without it the witness
+/// can never be recovered and adaptor swaps cannot be claimed.
+mod adaptor {
+	use super::{HashedProof, AdaptorSignature, SchnorrSignature};
+
+	/// Verify an adaptor signature and recover the witness scalar `t` behind `statement`.
+	///
+	/// Checks the encryption relation `R = R' + T` and the Schnorr equation `s·G = R + e·P` with
+	/// `e = H(R‖P‖m)`, recovers `t = s − s' (mod n)` and confirms `t·G == T`. Returns the
+	/// big-endian encoding of `t` on success, or `None` if any check fails.
+	#[cfg(feature = "secp256k1")]
+	pub fn recover_witness(
+		statement: &[u8; 33],
+		pubkey: &[u8; 33],
+		message: &[u8],
+		pre_signature: &AdaptorSignature,
+		signature: &SchnorrSignature,
+	) -> Option<HashedProof> {
+		use k256::elliptic_curve::{
+			sec1::FromEncodedPoint,
+			group::GroupEncoding,
+		};
+		use k256::{AffinePoint, EncodedPoint, ProjectivePoint, Scalar};
+		use sp_io::hashing::sha2_256;
+
+		let point = |bytes: &[u8; 33]| -> Option<ProjectivePoint> {
+			let encoded = EncodedPoint::from_bytes(&bytes[..]).ok()?;
+			let affine = Option::<AffinePoint>::from(AffinePoint::from_encoded_point(&encoded))?;
+			Some(ProjectivePoint::from(affine))
+		};
+		let scalar = |bytes: &[u8; 32]| -> Option<Scalar> {
+			Option::<Scalar>::from(Scalar::from_repr((*bytes).into()))
+		};
+
+		let big_t = point(statement)?;
+		let big_p = point(pubkey)?;
+		let big_r = point(&signature.r)?;
+		let big_r_pre = point(&pre_signature.r)?;
+		let s = scalar(&signature.s)?;
+		let s_pre = scalar(&pre_signature.s)?;
+
+		// Encryption relation: R = R' + T.
+		if big_r != big_r_pre + big_t {
+			return None
+		}
+
+		// Challenge e = H(R‖P‖m). Reduce the digest mod n rather than going through
+		// `from_repr`, which would reject (return `None` for) any digest that happens to be >= the
+		// group order instead of wrapping it — a legitimately produced signature must never fail
+		// here, and the reduction must match a counterparty that also reduces.
+		let mut preimage = Vec::with_capacity(33 + 33 + message.len());
+		preimage.extend_from_slice(&signature.r[..]);
+		preimage.extend_from_slice(&pubkey[..]);
+		preimage.extend_from_slice(message);
+		let e = Scalar::from_bytes_reduced(&sha2_256(&preimage).into());
+
+		// Schnorr equation: s·G = R + e·P.
+		if ProjectivePoint::GENERATOR * s != big_r + big_p * e {
+			return None
+		}
+
+		// Recover witness t = s − s' and confirm t·G == T.
+		let t = s - s_pre;
+		if ProjectivePoint::GENERATOR * t != big_t {
+			return None
+		}
+
+		Some(t.to_bytes().into())
+	}
+
+	/// Without the `secp256k1` feature the witness can never be recovered, so adaptor swaps are
+	/// unclaimable.
+	#[cfg(not(feature = "secp256k1"))]
+	pub fn recover_witness(
+		_statement: &[u8; 33],
+		_pubkey: &[u8; 33],
+		_message: &[u8],
+		_pre_signature: &AdaptorSignature,
+		_signature: &SchnorrSignature,
+	) -> Option<HashedProof> {
+		None
+	}
+}
+
 pub use pallet::*;
 
 #[frame_support::pallet(AtomicSwap)]
@@ -149,6 +471,13 @@ mod pallet {
 	pub trait Trait: frame_system::Trait {
 		/// The overarching event type.
 		type Event: From<Event<Self>> + IsType<<Self as frame_system::Trait>::Event>;
+		/// The runtime call type that a [`CallSwapAction`] may dispatch on claim.
+		type Call: Parameter
+			+ Dispatchable<Origin = <Self as frame_system::Trait>::Origin>
+			+ GetDispatchInfo;
+		/// Filter restricting which calls may be wrapped in a [`CallSwapAction`], letting a
+		/// runtime whitelist the transactions that are allowed to be swapped.
+		type CallFilter: Filter<<Self as Trait>::Call>;
 		/// Swap action.
 		type SwapAction: SwapAction<Self::AccountId, Self> + Parameter;
 		/// Limit of proof size.
@@ -166,7 +495,7 @@ mod pallet {
 
 	#[pallet::storage]
 	pub type PendingSwaps<T: Trait> = StorageDoubleMapType<
-		_, Twox64Concat, T::AccountId, Blake2_128Concat, HashedProof, PendingSwap<T>
+		_, Twox64Concat, T::AccountId, Blake2_128Concat, SwapId, PendingSwap<T>
 	>;
 
 	#[pallet::error]
@@ -187,19 +516,28 @@ mod pallet {
 		ClaimActionMismatch,
 		/// Duration has not yet passed for the swap to be cancelled.
 		DurationNotPassed,
+		/// Adaptor claim failed: the swap's action has no adaptor-signature path, the signature did
+		/// not verify so no witness could be recovered, or the reserved funds could not be released.
+		InvalidAdaptorSignature,
+		/// Swap cannot be settled through the hash-preimage path; it requires `claim_swap_adaptor`.
+		HashClaimUnsupported,
 	}
 
 	/// Event of atomic swap pallet.
 	#[pallet::event]
 	#[pallet::generate(pub(crate) fn deposit_event)]
 	pub enum Event<T: Trait> {
-		/// Swap created. \[account, proof, swap\]
-		NewSwap(T::AccountId, HashedProof, PendingSwap<T>),
+		/// Swap created. \[account, swap_id, swap\]
+		NewSwap(T::AccountId, SwapId, PendingSwap<T>),
 		/// Swap claimed. The last parameter indicates whether the execution succeeds.
-		/// \[account, proof, success\]
-		SwapClaimed(T::AccountId, HashedProof, bool),
-		/// Swap cancelled. \[account, proof\]
-		SwapCancelled(T::AccountId, HashedProof),
+		/// \[account, swap_id, success\]
+		SwapClaimed(T::AccountId, SwapId, bool),
+		/// Swap cancelled. \[account, swap_id\]
+		SwapCancelled(T::AccountId, SwapId),
+		/// Swap refunded early by the target. \[target, swap_id\]
+		SwapRefunded(T::AccountId, SwapId),
+		/// Adaptor swap claimed, revealing the witness scalar `t`. \[target, swap_id, witness\]
+		AdaptorSwapClaimed(T::AccountId, SwapId, HashedProof),
 	}
 
 	#[pallet::module]
@@ -218,8 +556,14 @@ mod pallet {
 		/// The dispatch origin for this call must be _Signed_.
 		///
 		/// - `target`: Receiver of the atomic swap.
-		/// - `hashed_proof`: The blake2_256 hash of the secret proof.
-		/// - `balance`: Funds to be sent from origin.
+		/// - `swap_id`: Identifier of the swap, agreed between the parties during setup. It must be
+		///   unique for the given `target`, which is what allows several concurrent swaps between
+		///   the same two accounts.
+		/// - `hashed_proof`: The hash of the secret proof, produced with `hash_algorithm`.
+		/// - `action`: Action defined in the swap.
+		/// - `hash_algorithm`: Algorithm the counterparty uses to hash the secret preimage. For a
+		///   same-chain swap this is `Blake2_256`; for a cross-chain swap it must match the hash
+		///   lock on the counterparty chain.
 		/// - `duration`: Locked duration of the atomic swap. For safety reasons, it is recommended
 		///   that the revealer uses a shorter duration than the counterparty, to prevent the
 		///   situation where the revealer reveals the proof too late around the end block.
@@ -227,13 +571,15 @@ mod pallet {
 		pub(crate) fn create_swap(
 			origin: OriginFor<T>,
 			target: T::AccountId,
+			swap_id: SwapId,
 			hashed_proof: HashedProof,
 			action: T::SwapAction,
+			hash_algorithm: HashAlgorithm,
 			duration: T::BlockNumber,
 		) -> DispatchResultWithPostInfo {
 			let source = ensure_signed(origin)?;
 			ensure!(
-				!PendingSwaps::<T>::contains_key(&target, hashed_proof),
+				!PendingSwaps::<T>::contains_key(&target, swap_id),
 				Error::<T>::AlreadyExist
 			);
 
@@ -242,12 +588,14 @@ mod pallet {
 			let swap = PendingSwap {
 				source,
 				action,
+				hashed_proof,
+				hash_algorithm,
 				end_block: frame_system::Module::<T>::block_number() + duration,
 			};
-			PendingSwaps::<T>::insert(target.clone(), hashed_proof.clone(), swap.clone());
+			PendingSwaps::<T>::insert(target.clone(), swap_id.clone(), swap.clone());
 
 			Self::deposit_event(
-				Event::NewSwap(target, hashed_proof, swap)
+				Event::NewSwap(target, swap_id, swap)
 			);
 
 			Ok(().into())
@@ -257,7 +605,9 @@ mod pallet {
 		///
 		/// The dispatch origin for this call must be _Signed_.
 		///
-		/// - `proof`: Revealed proof of the claim.
+		/// - `swap_id`: Identifier of the swap to claim, agreed during setup.
+		/// - `proof`: Revealed proof of the claim. It is hashed with the algorithm recorded on the
+		///   stored swap and must match the swap's `hashed_proof`.
 		/// - `action`: Action defined in the swap, it must match the entry in blockchain. Otherwise
 		///   the operation fails. This is used for weight calculation.
 		#[pallet::weight(
@@ -268,6 +618,7 @@ mod pallet {
 		)]
 		pub(crate) fn claim_swap(
 			origin: OriginFor<T>,
+			swap_id: SwapId,
 			proof: Vec<u8>,
 			action: T::SwapAction,
 		) -> DispatchResultWithPostInfo {
@@ -277,18 +628,60 @@ mod pallet {
 			);
 
 			let target = ensure_signed(origin)?;
-			let hashed_proof = blake2_256(&proof);
 
-			let swap = PendingSwaps::<T>::get(&target, hashed_proof)
-				.ok_or(Error::<T>::InvalidProof)?;
+			let swap = PendingSwaps::<T>::get(&target, swap_id)
+				.ok_or(Error::<T>::NotExist)?;
+			ensure!(
+				swap.action.supports_hash_claim(),
+				Error::<T>::HashClaimUnsupported,
+			);
+			ensure!(
+				swap.hash_algorithm.hash(&proof) == swap.hashed_proof,
+				Error::<T>::InvalidProof,
+			);
 			ensure!(swap.action == action, Error::<T>::ClaimActionMismatch);
 
 			let succeeded = swap.action.claim(&swap.source, &target);
 
-			PendingSwaps::<T>::remove(target.clone(), hashed_proof.clone());
+			PendingSwaps::<T>::remove(target.clone(), swap_id.clone());
+
+			Self::deposit_event(
+				Event::SwapClaimed(target, swap_id, succeeded)
+			);
+
+			Ok(().into())
+		}
+
+		/// Claim an atomic swap settled by an adaptor signature.
+		///
+		/// The dispatch origin for this call must be _Signed_ by the swap's target.
+		///
+		/// - `swap_id`: Identifier of the swap to claim.
+		/// - `pre_signature`: The pre-adaptor signature `(R', s')` agreed during setup.
+		/// - `signature`: The completed Schnorr signature `(R, s)` over the stored message.
+		///
+		/// On success the reserved funds are released and the recovered witness scalar `t` is
+		/// emitted in `AdaptorSwapClaimed`, so the counterparty chain can finalize its leg.
+		#[pallet::weight(T::DbWeight::get().reads_writes(1, 1).saturating_add(100_000_000))]
+		pub(crate) fn claim_swap_adaptor(
+			origin: OriginFor<T>,
+			swap_id: SwapId,
+			pre_signature: AdaptorSignature,
+			signature: SchnorrSignature,
+		) -> DispatchResultWithPostInfo {
+			let target = ensure_signed(origin)?;
+
+			let swap = PendingSwaps::<T>::get(&target, swap_id)
+				.ok_or(Error::<T>::NotExist)?;
+
+			let witness = swap.action
+				.claim_adaptor(&swap.source, &target, &pre_signature, &signature)
+				.ok_or(Error::<T>::InvalidAdaptorSignature)?;
+
+			PendingSwaps::<T>::remove(target.clone(), swap_id.clone());
 
 			Self::deposit_event(
-				Event::SwapClaimed(target, hashed_proof, succeeded)
+				Event::AdaptorSwapClaimed(target, swap_id, witness)
 			);
 
 			Ok(().into())
@@ -299,16 +692,16 @@ mod pallet {
 		/// The dispatch origin for this call must be _Signed_.
 		///
 		/// - `target`: Target of the original atomic swap.
-		/// - `hashed_proof`: Hashed proof of the original atomic swap.
+		/// - `swap_id`: Identifier of the original atomic swap.
 		#[pallet::weight(T::DbWeight::get().reads_writes(1, 1).saturating_add(40_000_000))]
 		pub(crate) fn cancel_swap(
 			origin: OriginFor<T>,
 			target: T::AccountId,
-			hashed_proof: HashedProof,
+			swap_id: SwapId,
 		) -> DispatchResultWithPostInfo {
 			let source = ensure_signed(origin)?;
 
-			let swap = PendingSwaps::<T>::get(&target, hashed_proof)
+			let swap = PendingSwaps::<T>::get(&target, swap_id)
 				.ok_or(Error::<T>::NotExist)?;
 			ensure!(
 				swap.source == source,
@@ -320,10 +713,37 @@ mod pallet {
 			);
 
 			swap.action.cancel(&swap.source);
-			PendingSwaps::<T>::remove(&target, hashed_proof.clone());
+			PendingSwaps::<T>::remove(&target, swap_id.clone());
+
+			Self::deposit_event(
+				Event::SwapCancelled(target, swap_id)
+			);
+
+			Ok(().into())
+		}
+
+		/// Refund an atomic swap early. Callable by the `target` of a pending swap that has decided
+		/// not to proceed, releasing the source's reserved funds immediately instead of forcing
+		/// both sides to wait out the lock.
+		///
+		/// The dispatch origin for this call must be _Signed_ by the swap's target.
+		///
+		/// - `swap_id`: Identifier of the atomic swap to refund.
+		#[pallet::weight(T::DbWeight::get().reads_writes(1, 1).saturating_add(40_000_000))]
+		pub(crate) fn refund_swap(
+			origin: OriginFor<T>,
+			swap_id: SwapId,
+		) -> DispatchResultWithPostInfo {
+			let target = ensure_signed(origin)?;
+
+			let swap = PendingSwaps::<T>::get(&target, swap_id)
+				.ok_or(Error::<T>::NotExist)?;
+
+			swap.action.cancel(&swap.source);
+			PendingSwaps::<T>::remove(&target, swap_id.clone());
 
 			Self::deposit_event(
-				Event::SwapCancelled(target, hashed_proof)
+				Event::SwapRefunded(target, swap_id)
 			);
 
 			Ok(().into())
@@ -0,0 +1,494 @@
+#![cfg(test)]
+
+use super::*;
+
+use frame_support::{
+	impl_outer_origin, impl_outer_dispatch, parameter_types, weights::Weight,
+};
+use sp_core::H256;
+use sp_runtime::{Perbill, traits::{BlakeTwo256, IdentityLookup}, testing::Header};
+
+impl_outer_origin! {
+	pub enum Origin for Test where system = frame_system {}
+}
+
+impl_outer_dispatch! {
+	pub enum Call for Test where origin: Origin {
+		pallet_balances::Balances,
+	}
+}
+
+#[derive(Clone, Eq, PartialEq)]
+pub struct Test;
+
+parameter_types! {
+	pub const BlockHashCount: u64 = 250;
+	pub const MaximumBlockWeight: Weight = 1024;
+	pub const MaximumBlockLength: u32 = 2 * 1024;
+	pub const AvailableBlockRatio: Perbill = Perbill::one();
+}
+impl frame_system::Trait for Test {
+	type Origin = Origin;
+	type Index = u64;
+	type BlockNumber = u64;
+	type Call = Call;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = u64;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Header = Header;
+	type Event = ();
+	type BlockHashCount = BlockHashCount;
+	type MaximumBlockWeight = MaximumBlockWeight;
+	type DbWeight = ();
+	type BlockExecutionWeight = ();
+	type ExtrinsicBaseWeight = ();
+	type MaximumExtrinsicWeight = MaximumBlockWeight;
+	type AvailableBlockRatio = AvailableBlockRatio;
+	type MaximumBlockLength = MaximumBlockLength;
+	type Version = ();
+	type ModuleToIndex = ();
+	type AccountData = pallet_balances::AccountData<u64>;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+}
+
+parameter_types! {
+	pub const ExistentialDeposit: u64 = 1;
+}
+impl pallet_balances::Trait for Test {
+	type Balance = u64;
+	type Event = ();
+	type DustRemoval = ();
+	type ExistentialDeposit = ExistentialDeposit;
+	type AccountStore = System;
+	type WeightInfo = ();
+	type MaxLocks = ();
+}
+
+/// Call filter used by the mock: every call may be wrapped in a `CallSwapAction`.
+pub struct AllowAll;
+impl Filter<Call> for AllowAll {
+	fn filter(_call: &Call) -> bool {
+		true
+	}
+}
+
+parameter_types! {
+	pub const ProofLimit: u32 = 1024;
+}
+impl Trait for Test {
+	type Event = ();
+	type Call = Call;
+	type CallFilter = AllowAll;
+	type SwapAction = BalanceSwapAction<u64, Balances>;
+	type ProofLimit = ProofLimit;
+}
+
+type System = frame_system::Module<Test>;
+type Balances = pallet_balances::Module<Test>;
+type AtomicSwap = Module<Test>;
+
+const A: u64 = 1;
+const B: u64 = 2;
+const SWAP_ID: SwapId = [7u8; 32];
+
+fn new_test_ext() -> sp_io::TestExternalities {
+	let mut t = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+	pallet_balances::GenesisConfig::<Test> {
+		balances: vec![(A, 100), (B, 100)],
+	}.assimilate_storage(&mut t).unwrap();
+	t.into()
+}
+
+fn action(value: u64) -> BalanceSwapAction<u64, Balances> {
+	BalanceSwapAction::new(value)
+}
+
+#[test]
+fn two_party_successful_swap() {
+	new_test_ext().execute_with(|| {
+		let proof = vec![1, 2, 3];
+		let hashed_proof = HashAlgorithm::Blake2_256.hash(&proof);
+
+		AtomicSwap::create_swap(
+			Origin::signed(A), B, SWAP_ID, hashed_proof, action(50), HashAlgorithm::Blake2_256, 1000,
+		).unwrap();
+		assert_eq!(Balances::free_balance(A), 50);
+		assert_eq!(Balances::reserved_balance(A), 50);
+
+		AtomicSwap::claim_swap(Origin::signed(B), SWAP_ID, proof, action(50)).unwrap();
+		assert_eq!(Balances::free_balance(A), 50);
+		assert_eq!(Balances::reserved_balance(A), 0);
+		assert_eq!(Balances::free_balance(B), 150);
+		assert!(PendingSwaps::<Test>::get(&B, SWAP_ID).is_none());
+	});
+}
+
+#[test]
+fn claim_honours_recorded_hash_algorithm() {
+	new_test_ext().execute_with(|| {
+		// The swap is locked with a non-native (Bitcoin-style double-SHA-256) hash; the preimage
+		// must be re-hashed with the recorded algorithm, not blake2, for the claim to match.
+		let proof = vec![9, 8, 7, 6];
+		let hashed_proof = HashAlgorithm::Sha2_256d.hash(&proof);
+		assert_ne!(hashed_proof, HashAlgorithm::Blake2_256.hash(&proof));
+
+		AtomicSwap::create_swap(
+			Origin::signed(A), B, SWAP_ID, hashed_proof, action(40), HashAlgorithm::Sha2_256d, 1000,
+		).unwrap();
+
+		AtomicSwap::claim_swap(Origin::signed(B), SWAP_ID, proof, action(40)).unwrap();
+		assert_eq!(Balances::reserved_balance(A), 0);
+		assert_eq!(Balances::free_balance(B), 140);
+	});
+}
+
+#[test]
+fn claim_rejects_preimage_hashed_with_wrong_algorithm() {
+	new_test_ext().execute_with(|| {
+		let proof = vec![5, 5, 5];
+		// Lock with keccak-256 but the stored digest is the sha2-256 one: the recorded algorithm
+		// re-hashes to keccak, which cannot match.
+		let hashed_proof = HashAlgorithm::Sha2_256.hash(&proof);
+
+		AtomicSwap::create_swap(
+			Origin::signed(A), B, SWAP_ID, hashed_proof, action(30), HashAlgorithm::Keccak256, 1000,
+		).unwrap();
+
+		assert_eq!(
+			AtomicSwap::claim_swap(Origin::signed(B), SWAP_ID, proof, action(30)),
+			Err(Error::<Test>::InvalidProof.into()),
+		);
+		assert_eq!(Balances::reserved_balance(A), 30);
+	});
+}
+
+#[test]
+fn same_hash_distinct_swap_ids_do_not_collide() {
+	new_test_ext().execute_with(|| {
+		let proof = vec![1, 1, 1];
+		let hashed_proof = HashAlgorithm::Blake2_256.hash(&proof);
+		let other_id: SwapId = [8u8; 32];
+
+		// Two concurrent swaps to the same target reusing the same hashed proof: keyed by swap id,
+		// they no longer collide.
+		AtomicSwap::create_swap(
+			Origin::signed(A), B, SWAP_ID, hashed_proof, action(20), HashAlgorithm::Blake2_256, 1000,
+		).unwrap();
+		AtomicSwap::create_swap(
+			Origin::signed(A), B, other_id, hashed_proof, action(30), HashAlgorithm::Blake2_256, 1000,
+		).unwrap();
+		assert_eq!(Balances::reserved_balance(A), 50);
+
+		// Reusing an existing swap id for the same target is what collides now.
+		assert_eq!(
+			AtomicSwap::create_swap(
+				Origin::signed(A), B, SWAP_ID, hashed_proof, action(10), HashAlgorithm::Blake2_256, 1000,
+			),
+			Err(Error::<Test>::AlreadyExist.into()),
+		);
+	});
+}
+
+#[test]
+fn target_can_refund_before_timelock() {
+	new_test_ext().execute_with(|| {
+		let proof = vec![4, 2];
+		let hashed_proof = HashAlgorithm::Blake2_256.hash(&proof);
+
+		AtomicSwap::create_swap(
+			Origin::signed(A), B, SWAP_ID, hashed_proof, action(60), HashAlgorithm::Blake2_256, 1000,
+		).unwrap();
+		assert_eq!(Balances::reserved_balance(A), 60);
+
+		// The source cannot cancel yet — the duration has not passed.
+		assert_eq!(
+			AtomicSwap::cancel_swap(Origin::signed(A), B, SWAP_ID),
+			Err(Error::<Test>::DurationNotPassed.into()),
+		);
+
+		// But the target may refund immediately, releasing the reservation in the same block.
+		AtomicSwap::refund_swap(Origin::signed(B), SWAP_ID).unwrap();
+		assert_eq!(Balances::reserved_balance(A), 0);
+		assert_eq!(Balances::free_balance(A), 100);
+		assert!(PendingSwaps::<Test>::get(&B, SWAP_ID).is_none());
+	});
+}
+
+#[test]
+fn only_target_can_refund() {
+	new_test_ext().execute_with(|| {
+		let hashed_proof = HashAlgorithm::Blake2_256.hash(&[0u8]);
+		AtomicSwap::create_swap(
+			Origin::signed(A), B, SWAP_ID, hashed_proof, action(10), HashAlgorithm::Blake2_256, 1000,
+		).unwrap();
+
+		// The source is not the target: it holds no swap under its own (target) key, so there is
+		// nothing for it to refund.
+		assert_eq!(
+			AtomicSwap::refund_swap(Origin::signed(A), SWAP_ID),
+			Err(Error::<Test>::NotExist.into()),
+		);
+		assert_eq!(Balances::reserved_balance(A), 10);
+	});
+}
+
+#[test]
+fn call_swap_action_releases_bond_on_claim() {
+	new_test_ext().execute_with(|| {
+		// A call action that moves 5 units from the source to the target when claimed, backed by a
+		// 40-unit native bond.
+		let call = Box::new(Call::Balances(pallet_balances::Call::transfer(B, 5)));
+		let action = CallSwapAction::<Test, Balances>::new(call, 40);
+
+		action.reserve(&A).unwrap();
+		assert_eq!(Balances::reserved_balance(A), 40);
+
+		assert!(action.claim(&A, &B));
+		// The bond is returned on the claim path, not only on cancel, and the stored call ran.
+		assert_eq!(Balances::reserved_balance(A), 0);
+		assert_eq!(Balances::free_balance(A), 95);
+		assert_eq!(Balances::free_balance(B), 105);
+	});
+}
+
+#[test]
+fn call_swap_action_returns_bond_on_cancel() {
+	new_test_ext().execute_with(|| {
+		let call = Box::new(Call::Balances(pallet_balances::Call::transfer(B, 5)));
+		let action = CallSwapAction::<Test, Balances>::new(call, 40);
+
+		action.reserve(&A).unwrap();
+		action.cancel(&A);
+		assert_eq!(Balances::reserved_balance(A), 0);
+		assert_eq!(Balances::free_balance(A), 100);
+	});
+}
+
+fn adaptor_action(message: Vec<u8>, value: u64) -> AdaptorSwapAction<u64, Balances> {
+	AdaptorSwapAction::new([2u8; 33], [3u8; 33], message, value)
+}
+
+#[test]
+fn adaptor_action_is_not_hash_claimable() {
+	new_test_ext().execute_with(|| {
+		let action = adaptor_action(vec![1, 2, 3], 50);
+		SwapAction::<u64, Test>::reserve(&action, &A).unwrap();
+
+		// The plain hash-preimage path can never settle an adaptor swap, and the swap advertises
+		// this so claim_swap refuses to consume (and delete) it.
+		assert!(!SwapAction::<u64, Test>::supports_hash_claim(&action));
+		assert!(!SwapAction::<u64, Test>::claim(&action, &A, &B));
+		assert_eq!(Balances::reserved_balance(A), 50);
+	});
+}
+
+#[test]
+fn adaptor_claim_with_malformed_signatures_releases_nothing() {
+	new_test_ext().execute_with(|| {
+		let action = adaptor_action(vec![1, 2, 3], 50);
+		SwapAction::<u64, Test>::reserve(&action, &A).unwrap();
+
+		let pre = AdaptorSignature { r: [0u8; 33], s: [0u8; 32] };
+		let sig = SchnorrSignature { r: [0u8; 33], s: [0u8; 32] };
+		// No valid signature ⇒ no witness ⇒ nothing released. This is the core invariant: a claim
+		// is impossible without publishing a signature that reveals `t`.
+		assert!(SwapAction::<u64, Test>::claim_adaptor(&action, &A, &B, &pre, &sig).is_none());
+		assert_eq!(Balances::reserved_balance(A), 50);
+	});
+}
+
+#[test]
+fn adaptor_action_rejects_oversized_message() {
+	new_test_ext().execute_with(|| {
+		let action = adaptor_action(vec![0u8; ADAPTOR_MESSAGE_LIMIT + 1], 50);
+		assert!(SwapAction::<u64, Test>::reserve(&action, &A).is_err());
+		assert_eq!(Balances::reserved_balance(A), 0);
+	});
+}
+
+// A second runtime whose swap action is `CallSwapAction`, so the dispatchable path and the
+// filter branch can be driven end to end. Its filter only whitelists balance transfers.
+impl_outer_origin! {
+	pub enum Origin2 for CallTest where system = frame_system {}
+}
+impl_outer_dispatch! {
+	pub enum Call2 for CallTest where origin: Origin2 {
+		frame_system::CSystem,
+		pallet_balances::CBalances,
+	}
+}
+
+#[derive(Clone, Eq, PartialEq)]
+pub struct CallTest;
+
+impl frame_system::Trait for CallTest {
+	type Origin = Origin2;
+	type Index = u64;
+	type BlockNumber = u64;
+	type Call = Call2;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = u64;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Header = Header;
+	type Event = ();
+	type BlockHashCount = BlockHashCount;
+	type MaximumBlockWeight = MaximumBlockWeight;
+	type DbWeight = ();
+	type BlockExecutionWeight = ();
+	type ExtrinsicBaseWeight = ();
+	type MaximumExtrinsicWeight = MaximumBlockWeight;
+	type AvailableBlockRatio = AvailableBlockRatio;
+	type MaximumBlockLength = MaximumBlockLength;
+	type Version = ();
+	type ModuleToIndex = ();
+	type AccountData = pallet_balances::AccountData<u64>;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+}
+
+impl pallet_balances::Trait for CallTest {
+	type Balance = u64;
+	type Event = ();
+	type DustRemoval = ();
+	type ExistentialDeposit = ExistentialDeposit;
+	type AccountStore = CSystem;
+	type WeightInfo = ();
+	type MaxLocks = ();
+}
+
+/// Filter that only permits balance transfers to be wrapped in a `CallSwapAction`.
+pub struct OnlyTransfer;
+impl Filter<Call2> for OnlyTransfer {
+	fn filter(call: &Call2) -> bool {
+		matches!(call, Call2::CBalances(pallet_balances::Call::transfer(..)))
+	}
+}
+
+impl Trait for CallTest {
+	type Event = ();
+	type Call = Call2;
+	type CallFilter = OnlyTransfer;
+	type SwapAction = CallSwapAction<CallTest, CBalances>;
+	type ProofLimit = ProofLimit;
+}
+
+type CSystem = frame_system::Module<CallTest>;
+type CBalances = pallet_balances::Module<CallTest>;
+type CAtomicSwap = Module<CallTest>;
+
+fn new_call_ext() -> sp_io::TestExternalities {
+	let mut t = frame_system::GenesisConfig::default().build_storage::<CallTest>().unwrap();
+	pallet_balances::GenesisConfig::<CallTest> {
+		balances: vec![(A, 100), (B, 100)],
+	}.assimilate_storage(&mut t).unwrap();
+	t.into()
+}
+
+#[test]
+fn call_swap_claimed_through_dispatchable_returns_bond() {
+	new_call_ext().execute_with(|| {
+		let proof = vec![1];
+		let hashed_proof = HashAlgorithm::Blake2_256.hash(&proof);
+		let call = Box::new(Call2::CBalances(pallet_balances::Call::transfer(B, 5)));
+		let act = CallSwapAction::<CallTest, CBalances>::new(call, 40);
+
+		CAtomicSwap::create_swap(
+			Origin2::signed(A), B, SWAP_ID, hashed_proof, act.clone(), HashAlgorithm::Blake2_256, 1000,
+		).unwrap();
+		assert_eq!(CBalances::reserved_balance(A), 40);
+
+		CAtomicSwap::claim_swap(Origin2::signed(B), SWAP_ID, proof, act).unwrap();
+		// Bond returned and the whitelisted transfer ran.
+		assert_eq!(CBalances::reserved_balance(A), 0);
+		assert_eq!(CBalances::free_balance(A), 95);
+		assert_eq!(CBalances::free_balance(B), 105);
+		assert!(PendingSwaps::<CallTest>::get(&B, SWAP_ID).is_none());
+	});
+}
+
+#[test]
+fn filtered_call_swap_is_rejected_and_reserves_nothing() {
+	new_call_ext().execute_with(|| {
+		let hashed_proof = HashAlgorithm::Blake2_256.hash(&[1]);
+		// A non-whitelisted call (system remark) can never be stored: the filter rejects it at
+		// creation, so no bond is ever reserved and none can be stranded.
+		let call = Box::new(Call2::CSystem(frame_system::Call::remark(vec![])));
+		let act = CallSwapAction::<CallTest, CBalances>::new(call, 40);
+
+		assert!(CAtomicSwap::create_swap(
+			Origin2::signed(A), B, SWAP_ID, hashed_proof, act, HashAlgorithm::Blake2_256, 1000,
+		).is_err());
+		assert_eq!(CBalances::reserved_balance(A), 0);
+		assert!(PendingSwaps::<CallTest>::get(&B, SWAP_ID).is_none());
+	});
+}
+
+#[test]
+#[cfg(feature = "secp256k1")]
+fn adaptor_claim_reveals_witness_and_moves_funds() {
+	use k256::{ProjectivePoint, Scalar};
+	use k256::elliptic_curve::{group::Curve, sec1::ToEncodedPoint};
+
+	let compress = |p: &ProjectivePoint| -> [u8; 33] {
+		let encoded = p.to_affine().to_encoded_point(true);
+		let mut out = [0u8; 33];
+		out.copy_from_slice(encoded.as_bytes());
+		out
+	};
+	let scalar_bytes = |s: &Scalar| -> [u8; 32] {
+		let mut out = [0u8; 32];
+		out.copy_from_slice(s.to_bytes().as_slice());
+		out
+	};
+
+	// Secret key, witness and nonce.
+	let x = Scalar::from(7u64);
+	let t = Scalar::from(13u64);
+	let r_pre = Scalar::from(5u64);
+
+	let big_p = ProjectivePoint::GENERATOR * x;
+	let big_t = ProjectivePoint::GENERATOR * t;
+	let big_r_pre = ProjectivePoint::GENERATOR * r_pre;
+	// R = R' + T.
+	let big_r = big_r_pre + big_t;
+
+	let message = b"cross-chain-leg".to_vec();
+
+	// Challenge e = H(R‖P‖m) reduced mod n, matching the on-chain derivation.
+	let r_bytes = compress(&big_r);
+	let p_bytes = compress(&big_p);
+	let mut preimage = Vec::new();
+	preimage.extend_from_slice(&r_bytes);
+	preimage.extend_from_slice(&p_bytes);
+	preimage.extend_from_slice(&message);
+	let e = Scalar::from_bytes_reduced(&sp_io::hashing::sha2_256(&preimage).into());
+
+	// Pre-adaptor signature s' = r' + e·x, completed signature s = s' + t.
+	let s_pre = r_pre + e * x;
+	let s = s_pre + t;
+
+	let pre_signature = AdaptorSignature { r: compress(&big_r_pre), s: scalar_bytes(&s_pre) };
+	let signature = SchnorrSignature { r: r_bytes, s: scalar_bytes(&s) };
+
+	new_test_ext().execute_with(|| {
+		let act = AdaptorSwapAction::<u64, Balances>::new(
+			compress(&big_t), p_bytes, message, 50,
+		);
+		SwapAction::<u64, Test>::reserve(&act, &A).unwrap();
+		assert_eq!(Balances::reserved_balance(A), 50);
+
+		let witness = SwapAction::<u64, Test>::claim_adaptor(&act, &A, &B, &pre_signature, &signature)
+			.expect("a valid adaptor signature recovers the witness");
+
+		// The revealed witness is exactly the discrete log of the statement point T.
+		assert_eq!(witness, scalar_bytes(&t));
+		// And the reserved funds moved to the target.
+		assert_eq!(Balances::reserved_balance(A), 0);
+		assert_eq!(Balances::free_balance(B), 150);
+	});
+}